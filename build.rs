@@ -0,0 +1,32 @@
+use std::process::Command;
+use time::OffsetDateTime;
+use time::macros::format_description;
+
+/// `git describe --tags --always --dirty` run against the checkout this
+/// binary was built from, so a log line or `-version` output can be traced
+/// back to the exact commit (and whether the tree was dirty). Falls back to
+/// "unknown" outside a git checkout or when `git` isn't on PATH.
+fn git_describe() -> String {
+    Command::new("git")
+        .args(["describe", "--tags", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn build_date() -> String {
+    OffsetDateTime::now_utc()
+        .format(&format_description!("[year]-[month]-[day] [hour]:[minute]:[second] UTC"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+fn main() {
+    println!("cargo:rustc-env=NORANEKO_GIT_DESCRIBE={}", git_describe());
+    println!("cargo:rustc-env=NORANEKO_BUILD_DATE={}", build_date());
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/index");
+}
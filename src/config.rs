@@ -6,6 +6,12 @@ use std::path::{Path, PathBuf};
 pub const BROWSER_NAME: &str = "Noraneko";
 pub const BROWSER_EXE: &str = "noraneko.exe";
 pub const DEFAULT_BRANCH: &str = "nightly";
+pub const DEFAULT_BACKUP_COUNT: u32 = 3;
+pub const DEFAULT_CHECK_INTERVAL_SECS: u64 = 3600;
+/// Default cap, in megabytes, on the zstd/xz decompression window - large
+/// enough for real-world browser archives without letting a hostile or
+/// malformed asset force unbounded memory use.
+pub const DEFAULT_MAX_DECOMPRESS_WINDOW_MB: u32 = 64;
 pub const CONFIG_FILE_NAME: &str = "Noraneko-WinUpdater.ini";
 pub const RELEASE_API_URL: &str = "https://api.github.com/repos/f3liz-dev/noraneko-runtime/releases";
 pub const CONNECT_CHECK_URL: &str = "https://api.github.com";
@@ -17,6 +23,11 @@ pub struct Config {
     pub update_self: bool,
     pub ignore_crl_errors: bool,
     pub branch: String,
+    pub backup_count: u32,
+    pub manifest_url: String,
+    pub trusted_public_key: String,
+    pub check_interval: u64,
+    pub max_decompress_window: u32,
     pub exe_dir: PathBuf,
     pub config_file: PathBuf,
 }
@@ -30,6 +41,11 @@ impl Config {
             update_self: true,
             ignore_crl_errors: false,
             branch: DEFAULT_BRANCH.to_string(),
+            backup_count: DEFAULT_BACKUP_COUNT,
+            manifest_url: String::new(),
+            trusted_public_key: String::new(),
+            check_interval: DEFAULT_CHECK_INTERVAL_SECS,
+            max_decompress_window: DEFAULT_MAX_DECOMPRESS_WINDOW_MB,
             exe_dir: exe_dir.clone(),
             config_file: exe_dir.join(CONFIG_FILE_NAME),
         };
@@ -83,6 +99,29 @@ impl Config {
                             cfg.branch = value;
                         }
                     }
+                    "backupcount" => {
+                        if let Ok(count) = value.parse() {
+                            cfg.backup_count = count;
+                        }
+                    }
+                    "manifesturl" => {
+                        cfg.manifest_url = value;
+                    }
+                    "trustedpublickey" => {
+                        cfg.trusted_public_key = value;
+                    }
+                    "checkinterval" => {
+                        if let Ok(interval) = value.parse() {
+                            cfg.check_interval = interval;
+                        }
+                    }
+                    "maxdecompresswindow" => {
+                        if let Ok(window) = value.parse::<u32>() {
+                            if window > 0 {
+                                cfg.max_decompress_window = window;
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -122,6 +161,11 @@ impl Config {
         });
 
         content.push_str(&format!("Branch={}\n", self.branch));
+        content.push_str(&format!("BackupCount={}\n", self.backup_count));
+        content.push_str(&format!("ManifestUrl={}\n", self.manifest_url));
+        content.push_str(&format!("TrustedPublicKey={}\n", self.trusted_public_key));
+        content.push_str(&format!("CheckInterval={}\n", self.check_interval));
+        content.push_str(&format!("MaxDecompressWindow={}\n", self.max_decompress_window));
         fs::write(&self.config_file, content)
     }
 
@@ -212,6 +256,42 @@ impl Config {
         let portable_path = self.exe_dir.join(format!("{BROWSER_NAME}-Portable.exe"));
         portable_path.exists()
     }
+
+    /// Apply one-off CLI overrides on top of the loaded config, reusing the
+    /// same validation rules `load` applies to the INI's `[Settings]`
+    /// values. Never calls `save`, so `Noraneko-WinUpdater.ini` keeps its
+    /// normal values after the process exits.
+    pub fn apply_overrides(&mut self, overrides: CliOverrides) {
+        if let Some(path) = overrides.path {
+            if path != "0" && !path.is_empty() {
+                self.path = path;
+            }
+        }
+        if let Some(work_dir) = overrides.work_dir {
+            if !work_dir.is_empty() {
+                self.work_dir = if work_dir == "." {
+                    self.exe_dir.clone()
+                } else {
+                    PathBuf::from(work_dir)
+                };
+            }
+        }
+        if let Some(branch) = overrides.branch {
+            if !branch.is_empty() {
+                self.branch = branch;
+            }
+        }
+    }
+}
+
+/// One-off overrides from CLI flags (`--branch=`, `--work-dir=`, `--path=`),
+/// applied in memory only so an operator can test an update against a
+/// different branch or work directory without disturbing the persisted INI.
+#[derive(Default)]
+pub struct CliOverrides {
+    pub path: Option<String>,
+    pub work_dir: Option<String>,
+    pub branch: Option<String>,
 }
 
 #[cfg(test)]
@@ -227,6 +307,9 @@ mod tests {
         let temp_dir = create_temp_dir();
         let cfg = Config::load(temp_dir.path()).expect("load config");
         assert_eq!(cfg.branch, DEFAULT_BRANCH);
+        assert_eq!(cfg.backup_count, DEFAULT_BACKUP_COUNT);
+        assert_eq!(cfg.check_interval, DEFAULT_CHECK_INTERVAL_SECS);
+        assert_eq!(cfg.max_decompress_window, DEFAULT_MAX_DECOMPRESS_WINDOW_MB);
         assert!(cfg.update_self);
         assert!(!cfg.ignore_crl_errors);
         assert!(cfg.config_file.exists());
@@ -241,6 +324,11 @@ WorkDir=D:\Temp
 UpdateSelf=0
 IgnoreCrlErrors=1
 Branch=beta
+BackupCount=5
+ManifestUrl=https://example.com/update.json
+TrustedPublicKey=RWtest0000000000000000000000000000000000000000000000000000000000
+CheckInterval=900
+MaxDecompressWindow=128
 ";
         let config_path = temp_dir.path().join(CONFIG_FILE_NAME);
         fs::write(&config_path, config_content).expect("write config");
@@ -251,6 +339,25 @@ Branch=beta
         assert!(!cfg.update_self);
         assert!(cfg.ignore_crl_errors);
         assert_eq!(cfg.branch, "beta");
+        assert_eq!(cfg.backup_count, 5);
+        assert_eq!(cfg.manifest_url, "https://example.com/update.json");
+        assert_eq!(
+            cfg.trusted_public_key,
+            "RWtest0000000000000000000000000000000000000000000000000000000000"
+        );
+        assert_eq!(cfg.check_interval, 900);
+        assert_eq!(cfg.max_decompress_window, 128);
+    }
+
+    #[test]
+    fn test_load_ignores_invalid_max_decompress_window() {
+        let temp_dir = create_temp_dir();
+        let config_content = "[Settings]\nMaxDecompressWindow=0\n";
+        let config_path = temp_dir.path().join(CONFIG_FILE_NAME);
+        fs::write(&config_path, config_content).expect("write config");
+
+        let cfg = Config::load(temp_dir.path()).expect("load config");
+        assert_eq!(cfg.max_decompress_window, DEFAULT_MAX_DECOMPRESS_WINDOW_MB);
     }
 
     #[test]
@@ -262,6 +369,11 @@ Branch=beta
             update_self: false,
             ignore_crl_errors: true,
             branch: "stable".to_string(),
+            backup_count: 5,
+            manifest_url: String::new(),
+            trusted_public_key: String::new(),
+            check_interval: DEFAULT_CHECK_INTERVAL_SECS,
+            max_decompress_window: DEFAULT_MAX_DECOMPRESS_WINDOW_MB,
             exe_dir: temp_dir.path().to_path_buf(),
             config_file: temp_dir.path().join(CONFIG_FILE_NAME),
         };
@@ -272,6 +384,41 @@ Branch=beta
         assert!(content.contains("UpdateSelf=0"));
         assert!(content.contains("IgnoreCrlErrors=1"));
         assert!(content.contains("Branch=stable"));
+        assert!(content.contains("CheckInterval=3600"));
+        assert!(content.contains("BackupCount=5"));
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let temp_dir = create_temp_dir();
+        let mut cfg = Config::load(temp_dir.path()).expect("load config");
+
+        cfg.apply_overrides(CliOverrides {
+            path: Some(r"C:\Test\noraneko.exe".to_string()),
+            work_dir: Some(".".to_string()),
+            branch: Some("beta".to_string()),
+        });
+
+        assert_eq!(cfg.path, r"C:\Test\noraneko.exe");
+        assert_eq!(cfg.work_dir, cfg.exe_dir);
+        assert_eq!(cfg.branch, "beta");
+    }
+
+    #[test]
+    fn test_apply_overrides_ignores_unset_values() {
+        let temp_dir = create_temp_dir();
+        let mut cfg = Config::load(temp_dir.path()).expect("load config");
+        let original_branch = cfg.branch.clone();
+
+        cfg.apply_overrides(CliOverrides {
+            path: Some("0".to_string()),
+            work_dir: Some(String::new()),
+            branch: None,
+        });
+
+        assert!(cfg.path.is_empty());
+        assert_eq!(cfg.work_dir, env::temp_dir());
+        assert_eq!(cfg.branch, original_branch);
     }
 
     #[test]
@@ -0,0 +1,4 @@
+pub mod config;
+pub mod manifest;
+pub mod signature;
+pub mod updater;
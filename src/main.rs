@@ -3,8 +3,10 @@ use std::path::PathBuf;
 
 use noraneko_winupdater::config;
 use noraneko_winupdater::updater::{Options, Updater};
+use semver::Version;
 
-const VERSION: &str = "1.0.0";
+const VERSION: &str = env!("NORANEKO_GIT_DESCRIBE");
+const BUILD_DATE: &str = env!("NORANEKO_BUILD_DATE");
 
 fn main() {
     let mut scheduled = false;
@@ -13,6 +15,14 @@ fn main() {
     let mut remove_task = false;
     let mut check_only = false;
     let mut version = false;
+    let mut require_signature = false;
+    let mut force = false;
+    let mut quiet = false;
+    let mut watch = false;
+    let mut list_versions = false;
+    let mut rollback = false;
+    let mut pin_version: Option<String> = None;
+    let mut overrides = config::CliOverrides::default();
 
     for arg in env::args().skip(1) {
         match arg.as_str() {
@@ -22,12 +32,33 @@ fn main() {
             "-remove-task" => remove_task = true,
             "-check-only" => check_only = true,
             "-version" => version = true,
-            _ => {}
+            "-require-signature" => require_signature = true,
+            "-force" => force = true,
+            "-quiet" => quiet = true,
+            "-watch" => watch = true,
+            "-list-versions" => list_versions = true,
+            "-rollback" => rollback = true,
+            _ => {
+                if let Some(value) = arg.strip_prefix("--branch=") {
+                    overrides.branch = Some(value.to_string());
+                } else if let Some(value) = arg.strip_prefix("--work-dir=") {
+                    overrides.work_dir = Some(value.to_string());
+                } else if let Some(value) = arg.strip_prefix("--path=") {
+                    overrides.path = Some(value.to_string());
+                } else if let Some(value) = arg.strip_prefix("--pin=") {
+                    pin_version = Some(value.to_string());
+                }
+            }
         }
     }
 
     if version {
-        println!("{} WinUpdater v{}", config::BROWSER_NAME, VERSION);
+        println!(
+            "{} WinUpdater v{} (built {})",
+            config::BROWSER_NAME,
+            VERSION,
+            BUILD_DATE
+        );
         return;
     }
 
@@ -43,13 +74,14 @@ fn main() {
         .map(PathBuf::from)
         .unwrap_or_else(|| PathBuf::from("."));
 
-    let cfg = match config::Config::load(&exe_dir) {
+    let mut cfg = match config::Config::load(&exe_dir) {
         Ok(cfg) => cfg,
         Err(err) => {
             eprintln!("Error loading configuration: {err}");
             std::process::exit(1);
         }
     };
+    cfg.apply_overrides(overrides);
 
     let mut updater = Updater::new(
         cfg,
@@ -60,6 +92,9 @@ fn main() {
             create_task,
             remove_task,
             version: VERSION.to_string(),
+            require_signature,
+            force,
+            quiet,
         },
     );
 
@@ -71,6 +106,52 @@ fn main() {
         return;
     }
 
+    if watch {
+        if let Err(err) = updater.run_watch() {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if list_versions {
+        match updater.list_versions() {
+            Ok(versions) => {
+                for v in versions {
+                    println!("{v}");
+                }
+            }
+            Err(err) => {
+                eprintln!("Error listing versions: {err}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if rollback {
+        if let Err(err) = updater.rollback() {
+            eprintln!("Error rolling back: {err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(pin) = pin_version {
+        let pinned_version = match Version::parse(pin.trim_start_matches('v')) {
+            Ok(v) => v,
+            Err(err) => {
+                eprintln!("Invalid version '{pin}': {err}");
+                std::process::exit(1);
+            }
+        };
+        if let Err(err) = updater.install_version(&pinned_version) {
+            eprintln!("Error installing version {pinned_version}: {err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     if let Err(err) = updater.run() {
         eprintln!("Error: {err}");
         std::process::exit(1);
@@ -0,0 +1,56 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// A signed update manifest (`update.json`) describing the latest version
+/// and, per platform, exactly where to fetch it and how to verify it. Using
+/// an explicit manifest avoids guessing the right release asset by matching
+/// filename substrings.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Manifest {
+    pub version: String,
+    pub pub_date: String,
+    pub platforms: HashMap<String, ManifestPlatform>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ManifestPlatform {
+    pub url: String,
+    #[serde(default)]
+    pub signature: String,
+    pub sha256: String,
+    pub format: String,
+}
+
+impl Manifest {
+    pub fn platform_for(&self, target: &str) -> Option<&ManifestPlatform> {
+        self.platforms.get(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let json = r#"{
+            "version": "1.5.0",
+            "pub_date": "2024-01-01T00:00:00Z",
+            "platforms": {
+                "windows-x86_64": {
+                    "url": "https://example.com/noraneko-1.5.0-windows-x86_64.zip",
+                    "signature": "untrusted comment: ...\nRW...",
+                    "sha256": "abc123",
+                    "format": "zip"
+                }
+            }
+        }"#;
+
+        let manifest: Manifest = serde_json::from_str(json).expect("parse manifest");
+        assert_eq!(manifest.version, "1.5.0");
+        let platform = manifest.platform_for("windows-x86_64").expect("platform entry");
+        assert_eq!(platform.format, "zip");
+        assert_eq!(platform.sha256, "abc123");
+        assert!(manifest.platform_for("windows-arm64").is_none());
+    }
+}